@@ -1,32 +1,112 @@
-use std::f32::consts::PI;
+use std::{collections::HashMap, f32::consts::PI};
 
 use crate::{
-    mesh::{Indices, Mesh, MeshBuilder, Meshable},
+    mesh::{Indices, Mesh, MeshBuilder, Meshable, VertexAttributeValues},
     render_asset::RenderAssetUsages,
 };
-use bevy_math::primitives::Ellipsoid;
+use bevy_math::{primitives::Ellipsoid, Vec3};
 use wgpu::PrimitiveTopology;
 
+/// The tessellation method used to generate an ellipsoid [`Mesh`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EllipsoidKind {
+    /// A UV-mapped ellipsoid, generated by sweeping latitude/longitude rings.
+    /// Cheap to generate, but crams many thin triangles at the poles.
+    Uv {
+        /// The number of longitudinal sectors, aka the horizontal resolution.
+        #[doc(alias = "horizontal_resolution")]
+        sectors: usize,
+        /// The number of latitudinal stacks, aka the vertical resolution.
+        #[doc(alias = "vertical_resolution")]
+        stacks: usize,
+    },
+    /// An ellipsoid tessellated from a subdivided icosahedron. Produces a much
+    /// more even triangle distribution than [`EllipsoidKind::Uv`], with no
+    /// pinched poles, at the cost of a slightly more expensive generation.
+    Ico {
+        /// The number of times each edge of the base icosahedron's 20 faces
+        /// is subdivided. A subdivision level of `n` splits every edge into
+        /// `n + 1` segments, producing `(n + 1)²` sub-triangles per face.
+        subdivisions: usize,
+    },
+}
+
+impl Default for EllipsoidKind {
+    fn default() -> Self {
+        Self::Uv {
+            sectors: 32,
+            stacks: 16,
+        }
+    }
+}
+
 /// Ellipsoid mesh options
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct EllipsoidOptions {
-    /// The number of longitudinal sectors, aka the horizontal resolution.
-    #[doc(alias = "horizontal_resolution")]
-    sectors: usize,
-    /// The number of latitudinal stacks, aka the vertical resolution.
-    #[doc(alias = "vertical_resolution")]
-    stacks: usize,
+    /// The tessellation method used to generate the ellipsoid mesh.
+    pub kind: EllipsoidKind,
 }
 
-impl Default for EllipsoidOptions {
+/// Options controlling [`EllipsoidMeshBuilder::displaced`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplacementOptions {
+    /// If `true`, keeps the ellipsoid's original UVs instead of recomputing
+    /// them from the displaced vertex positions.
+    pub keep_original_uvs: bool,
+}
+
+impl Default for DisplacementOptions {
     fn default() -> Self {
         Self {
-            sectors: 32,
-            stacks: 16,
+            keep_original_uvs: true,
         }
     }
 }
 
+/// The maximum number of triangles in a single [`EllipsoidMeshlet`].
+const MESHLET_MAX_TRIANGLES: usize = 64;
+/// The maximum number of unique vertices in a single [`EllipsoidMeshlet`].
+///
+/// Local vertex indices are packed into a `u8`, so this must never exceed `u8::MAX + 1`.
+const MESHLET_MAX_VERTICES: usize = 124;
+const _: () = assert!(MESHLET_MAX_VERTICES <= u8::MAX as usize + 1);
+
+/// A cluster of up to [`MESHLET_MAX_TRIANGLES`] triangles and
+/// [`MESHLET_MAX_VERTICES`] unique vertices of an ellipsoid mesh, for
+/// GPU-driven / mesh-shader rendering pipelines.
+///
+/// Produced by [`EllipsoidMeshBuilder::build_meshlets`] alongside the
+/// [`EllipsoidMeshlets`] arrays this descriptor indexes into.
+#[derive(Clone, Copy, Debug)]
+pub struct EllipsoidMeshlet {
+    /// Offset into [`EllipsoidMeshlets::vertex_ids`] of this meshlet's unique vertices.
+    pub vertex_offset: u32,
+    /// Number of unique vertices used by this meshlet.
+    pub vertex_count: u32,
+    /// Offset into [`EllipsoidMeshlets::triangles`] of this meshlet's local triangle indices.
+    pub triangle_offset: u32,
+    /// Number of triangles in this meshlet.
+    pub triangle_count: u32,
+    /// The center of this meshlet's bounding sphere, in mesh local space.
+    pub bounding_sphere_center: [f32; 3],
+    /// The radius of this meshlet's bounding sphere.
+    pub bounding_sphere_radius: f32,
+}
+
+/// The meshlet clustering of an ellipsoid mesh's triangle list, produced by
+/// [`EllipsoidMeshBuilder::build_meshlets`].
+#[derive(Clone, Debug, Default)]
+pub struct EllipsoidMeshlets {
+    /// One descriptor per meshlet.
+    pub meshlets: Vec<EllipsoidMeshlet>,
+    /// The mesh's global vertex indices referenced by all meshlets, indexed by
+    /// each [`EllipsoidMeshlet::vertex_offset`]/`vertex_count`.
+    pub vertex_ids: Vec<u32>,
+    /// Meshlet-local triangle indices (into that meshlet's slice of
+    /// `vertex_ids`), indexed by each [`EllipsoidMeshlet::triangle_offset`]/`triangle_count`.
+    pub triangles: Vec<u8>,
+}
+
 /// A builder used for creating a [`Mesh`] with an [`Ellipsoid`] shape.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct EllipsoidMeshBuilder {
@@ -36,14 +116,17 @@ pub struct EllipsoidMeshBuilder {
 }
 
 impl EllipsoidMeshBuilder {
-    /// Creates a new [`SphereMeshBuilder`] from a radius and [`SphereKind`].
+    /// Creates a new [`EllipsoidMeshBuilder`] from the semi-axes `a`, `b` and `c`,
+    /// using a [`EllipsoidKind::Uv`] tessellation of `32` sectors and `16` stacks.
     #[inline]
     pub const fn new(a: f32, b: f32, c: f32) -> Self {
         Self {
             ellipsoid: Ellipsoid { a, b, c },
             options: EllipsoidOptions {
-                sectors: 32,
-                stacks: 16,
+                kind: EllipsoidKind::Uv {
+                    sectors: 32,
+                    stacks: 16,
+                },
             },
         }
     }
@@ -118,17 +201,545 @@ impl EllipsoidMeshBuilder {
         .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
         .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
     }
+
+    /// Creates an ellipsoid [`Mesh`] tessellated from a subdivided icosahedron rather
+    /// than the latitude/longitude rings used by [`EllipsoidMeshBuilder::uv`].
+    ///
+    /// This produces a much more even triangle distribution, with no pinched poles,
+    /// which is desirable when the mesh will be displaced (e.g. for procedural
+    /// planets) or used to generate a physics collider.
+    ///
+    /// The `subdivisions` parameter controls how many times each edge of the base
+    /// icosahedron's 20 faces is split: a subdivision level of `n` splits every
+    /// edge into `n + 1` segments, producing `(n + 1)²` sub-triangles per face.
+    pub fn ico(&self, subdivisions: usize) -> Mesh {
+        let segments = (subdivisions + 1) as u32;
+        let (base_directions, base_faces) = icosahedron();
+
+        let mut directions: Vec<Vec3> = base_directions.to_vec();
+        let mut indices: Vec<u32> = Vec::new();
+        // Maps a canonical key for a point shared between faces (a point on
+        // an edge) to the index of its generated vertex, so adjacent faces
+        // don't duplicate the vertices along their shared edge.
+        let mut shared_edge_points: HashMap<(u32, u32, u32), u32> = HashMap::new();
+
+        for &[a, b, c] in &base_faces {
+            let corners = [a as u32, b as u32, c as u32];
+
+            // Generate the triangular grid of points covering this face, indexed
+            // by row `i` (0 at the edge opposite `a`, `segments` at `a` itself).
+            let mut grid: Vec<Vec<u32>> = Vec::with_capacity(segments as usize + 1);
+            for i in 0..=segments {
+                let row_len = segments - i + 1;
+                let mut row = Vec::with_capacity(row_len as usize);
+                for j in 0..row_len {
+                    let k = segments - i - j;
+                    row.push(grid_point_index(
+                        &mut directions,
+                        &mut shared_edge_points,
+                        corners,
+                        [i, j, k],
+                        segments,
+                    ));
+                }
+                grid.push(row);
+            }
+
+            // Triangulate the grid.
+            for i in 0..segments as usize {
+                let row_len = grid[i].len();
+                for j in 0..row_len - 1 {
+                    indices.push(grid[i][j]);
+                    indices.push(grid[i + 1][j]);
+                    indices.push(grid[i][j + 1]);
+
+                    if j + 1 < row_len - 1 {
+                        indices.push(grid[i][j + 1]);
+                        indices.push(grid[i + 1][j]);
+                        indices.push(grid[i + 1][j + 1]);
+                    }
+                }
+            }
+        }
+
+        self.ico_mesh_from_directions(directions, indices)
+    }
+
+    /// Pushes every vertex of `mesh` outward along its surface normal by
+    /// `displacement(direction)`, where `direction` is the vertex's normalized
+    /// pre-displacement surface normal.
+    ///
+    /// This is typically fed a fractal Brownian motion sampled from a noise
+    /// function to turn a smooth ellipsoid into mountainous terrain, e.g.
+    /// summing several octaves of simplex noise with `lacunarity ≈ 2.0` and
+    /// `gain ≈ 0.5`:
+    ///
+    /// ```ignore
+    /// let mut height = 0.0;
+    /// for octave in 0..6 {
+    ///     height += noise(direction * lacunarity.powi(octave)) * gain.powi(octave);
+    /// }
+    /// ```
+    ///
+    /// Because displacement invalidates the analytic ellipsoid normals, this
+    /// recomputes smooth per-vertex normals from the displaced geometry by
+    /// accumulating the cross product of each triangle's edges into its three
+    /// vertices and normalizing the result.
+    pub fn displaced(
+        &self,
+        mut mesh: Mesh,
+        displacement: impl Fn(Vec3) -> f32,
+        options: DisplacementOptions,
+    ) -> Mesh {
+        let (Some(VertexAttributeValues::Float32x3(positions)), Some(VertexAttributeValues::Float32x3(normals))) = (
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION).cloned(),
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL).cloned(),
+        ) else {
+            return mesh;
+        };
+
+        let mut displaced_positions: Vec<[f32; 3]> = positions
+            .iter()
+            .zip(&normals)
+            .map(|(position, normal)| {
+                let position = Vec3::from_array(*position);
+                let direction = Vec3::from_array(*normal).normalize();
+                (position + direction * displacement(direction)).to_array()
+            })
+            .collect();
+
+        let mut accumulated_normals = vec![Vec3::ZERO; displaced_positions.len()];
+        if let Some(Indices::U32(indices)) = mesh.indices() {
+            for triangle in indices.chunks_exact(3) {
+                let [i0, i1, i2] = [
+                    triangle[0] as usize,
+                    triangle[1] as usize,
+                    triangle[2] as usize,
+                ];
+                let p0 = Vec3::from_array(displaced_positions[i0]);
+                let p1 = Vec3::from_array(displaced_positions[i1]);
+                let p2 = Vec3::from_array(displaced_positions[i2]);
+                let face_normal = (p1 - p0).cross(p2 - p0);
+
+                accumulated_normals[i0] += face_normal;
+                accumulated_normals[i1] += face_normal;
+                accumulated_normals[i2] += face_normal;
+            }
+        }
+
+        let mut smoothed_normals: Vec<[f32; 3]> = accumulated_normals
+            .into_iter()
+            .map(|normal| normal.normalize_or_zero().to_array())
+            .collect();
+
+        if !options.keep_original_uvs {
+            let Ellipsoid { a, b, c } = self.ellipsoid;
+            let mut recomputed_uvs: Vec<[f32; 2]> = displaced_positions
+                .iter()
+                .map(|position| {
+                    let direction =
+                        Vec3::new(position[0] / a, position[1] / b, position[2] / c).normalize();
+                    [
+                        0.5 + direction.z.atan2(direction.x) / (2. * PI),
+                        0.5 - direction.y.clamp(-1.0, 1.0).asin() / PI,
+                    ]
+                })
+                .collect();
+
+            // Displacement moves both copies of a seam-duplicated vertex (which
+            // share a position) by the same amount, so recomputing `u` from the
+            // displaced position alone would collapse them back to a single
+            // value and lose the `duplicate_uv_seam` split. Re-run the seam pass
+            // here instead of trusting the recomputed UVs in isolation.
+            if let Some(Indices::U32(indices)) = mesh.indices().cloned() {
+                let mut indices = indices;
+                duplicate_uv_seam(
+                    &mut displaced_positions,
+                    &mut smoothed_normals,
+                    &mut recomputed_uvs,
+                    &mut indices,
+                );
+                mesh.insert_indices(Indices::U32(indices));
+            }
+
+            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, recomputed_uvs);
+        }
+
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, displaced_positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, smoothed_normals);
+
+        mesh
+    }
+
+    /// Builds a descending-detail sequence of `levels` ellipsoid meshes sharing
+    /// the same surface, starting from `self`'s configured [`EllipsoidKind`] and
+    /// reducing the tessellation at each subsequent level.
+    ///
+    /// For [`EllipsoidKind::Uv`], `sectors` and `stacks` are halved each level,
+    /// clamped to a minimum of `3` sectors and `2` stacks. For
+    /// [`EllipsoidKind::Ico`], the subdivision count is decreased by one each
+    /// level, clamped to a minimum of `0`.
+    ///
+    /// Each returned [`Mesh`] is self-contained, with its own indices, normals
+    /// and UVs, so callers can swap between them by camera distance.
+    ///
+    /// Stops early, returning fewer than `levels` meshes, once the tessellation
+    /// bottoms out (`3` sectors / `2` stacks, or `0` subdivisions) and a further
+    /// level would just duplicate the last one.
+    pub fn build_lods(&self, levels: usize) -> Vec<Mesh> {
+        let mut lods = Vec::with_capacity(levels);
+        let mut kind = self.options.kind;
+
+        for _ in 0..levels {
+            lods.push(match kind {
+                EllipsoidKind::Uv { sectors, stacks } => self.uv(sectors, stacks),
+                EllipsoidKind::Ico { subdivisions } => self.ico(subdivisions),
+            });
+
+            let next_kind = match kind {
+                EllipsoidKind::Uv { sectors, stacks } => EllipsoidKind::Uv {
+                    sectors: (sectors / 2).max(3),
+                    stacks: (stacks / 2).max(2),
+                },
+                EllipsoidKind::Ico { subdivisions } => EllipsoidKind::Ico {
+                    subdivisions: subdivisions.saturating_sub(1),
+                },
+            };
+
+            if next_kind == kind {
+                break;
+            }
+            kind = next_kind;
+        }
+
+        lods
+    }
+
+    /// Partitions `mesh`'s triangle list into [`EllipsoidMeshlets`] of up to
+    /// [`MESHLET_MAX_TRIANGLES`] triangles and [`MESHLET_MAX_VERTICES`] unique
+    /// vertices each, for GPU-driven / mesh-shader rendering pipelines.
+    ///
+    /// Clusters are built by greedily walking `mesh`'s index buffer and
+    /// starting a new meshlet whenever adding the next triangle would exceed
+    /// the vertex or triangle cap, remapping global vertex indices to
+    /// meshlet-local indices via a per-meshlet hash map. `mesh` is expected to
+    /// have been produced by this builder (e.g. via [`MeshBuilder::build`] or
+    /// [`EllipsoidMeshBuilder::ico`]).
+    pub fn build_meshlets(&self, mesh: &Mesh) -> EllipsoidMeshlets {
+        let (Some(Indices::U32(indices)), Some(positions)) = (
+            mesh.indices(),
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+                .and_then(VertexAttributeValues::as_float3),
+        ) else {
+            return EllipsoidMeshlets::default();
+        };
+
+        let mut meshlets = Vec::new();
+        let mut vertex_ids: Vec<u32> = Vec::new();
+        let mut triangles: Vec<u8> = Vec::new();
+
+        let mut local_indices: HashMap<u32, u8> = HashMap::new();
+        let mut vertex_start = 0;
+        let mut triangle_start = 0;
+
+        for triangle in indices.chunks_exact(3) {
+            let new_vertex_count = triangle
+                .iter()
+                .filter(|global| !local_indices.contains_key(global))
+                .count();
+            let would_exceed_vertices =
+                local_indices.len() + new_vertex_count > MESHLET_MAX_VERTICES;
+            let would_exceed_triangles =
+                (triangles.len() - triangle_start) / 3 >= MESHLET_MAX_TRIANGLES;
+
+            if !local_indices.is_empty() && (would_exceed_vertices || would_exceed_triangles) {
+                meshlets.push(finish_meshlet(
+                    vertex_start,
+                    triangle_start,
+                    &vertex_ids,
+                    &triangles,
+                    positions,
+                ));
+                local_indices.clear();
+                vertex_start = vertex_ids.len();
+                triangle_start = triangles.len();
+            }
+
+            for &global in triangle {
+                let local = *local_indices.entry(global).or_insert_with(|| {
+                    let local = (vertex_ids.len() - vertex_start) as u8;
+                    vertex_ids.push(global);
+                    local
+                });
+                triangles.push(local);
+            }
+        }
+
+        if !local_indices.is_empty() {
+            meshlets.push(finish_meshlet(
+                vertex_start,
+                triangle_start,
+                &vertex_ids,
+                &triangles,
+                positions,
+            ));
+        }
+
+        EllipsoidMeshlets {
+            meshlets,
+            vertex_ids,
+            triangles,
+        }
+    }
+
+    /// Builds the final ellipsoid [`Mesh`] from unit-sphere vertex directions and
+    /// their triangle indices, scaling positions by the ellipsoid's semi-axes,
+    /// deriving normals from the implicit ellipsoid gradient, and generating
+    /// spherical UVs with a seam-duplication pass.
+    fn ico_mesh_from_directions(&self, directions: Vec<Vec3>, mut indices: Vec<u32>) -> Mesh {
+        let Ellipsoid { a, b, c } = self.ellipsoid;
+
+        let mut positions: Vec<[f32; 3]> = Vec::with_capacity(directions.len());
+        let mut normals: Vec<[f32; 3]> = Vec::with_capacity(directions.len());
+        let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(directions.len());
+
+        for dir in &directions {
+            // The gradient `(x/a², y/b², z/c²)` evaluated at the scaled position
+            // `(dir.x * a, dir.y * b, dir.z * c)`.
+            let normal = Vec3::new(dir.x / a, dir.y / b, dir.z / c).normalize();
+
+            positions.push([dir.x * a, dir.y * b, dir.z * c]);
+            normals.push(normal.to_array());
+            uvs.push([
+                0.5 + dir.z.atan2(dir.x) / (2. * PI),
+                0.5 - dir.y.clamp(-1.0, 1.0).asin() / PI,
+            ]);
+        }
+
+        duplicate_uv_seam(&mut positions, &mut normals, &mut uvs, &mut indices);
+
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_indices(Indices::U32(indices))
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    }
+}
+
+/// Returns the 12 vertex directions and 20 triangular faces (as indices into
+/// those directions) of a unit icosahedron, used as the base mesh for
+/// [`EllipsoidMeshBuilder::ico`].
+fn icosahedron() -> ([Vec3; 12], [[usize; 3]; 20]) {
+    let t = (1.0 + 5f32.sqrt()) / 2.0;
+
+    let directions = [
+        Vec3::new(-1.0, t, 0.0),
+        Vec3::new(1.0, t, 0.0),
+        Vec3::new(-1.0, -t, 0.0),
+        Vec3::new(1.0, -t, 0.0),
+        Vec3::new(0.0, -1.0, t),
+        Vec3::new(0.0, 1.0, t),
+        Vec3::new(0.0, -1.0, -t),
+        Vec3::new(0.0, 1.0, -t),
+        Vec3::new(t, 0.0, -1.0),
+        Vec3::new(t, 0.0, 1.0),
+        Vec3::new(-t, 0.0, -1.0),
+        Vec3::new(-t, 0.0, 1.0),
+    ]
+    .map(Vec3::normalize);
+
+    let faces = [
+        [0, 11, 5],
+        [0, 5, 1],
+        [0, 1, 7],
+        [0, 7, 10],
+        [0, 10, 11],
+        [1, 5, 9],
+        [5, 11, 4],
+        [11, 10, 2],
+        [10, 7, 6],
+        [7, 1, 8],
+        [3, 9, 4],
+        [3, 4, 2],
+        [3, 2, 6],
+        [3, 6, 8],
+        [3, 8, 9],
+        [4, 9, 5],
+        [2, 4, 11],
+        [6, 2, 10],
+        [8, 6, 7],
+        [9, 8, 1],
+    ];
+
+    (directions, faces)
+}
+
+/// Returns the vertex index for the point at barycentric `weights` (summing to
+/// `segments`) on the face `corners`, generating and normalizing it onto the
+/// unit sphere if it hasn't been visited yet, and deduplicating points that lie
+/// on an edge shared with another face via `shared_edge_points`.
+fn grid_point_index(
+    directions: &mut Vec<Vec3>,
+    shared_edge_points: &mut HashMap<(u32, u32, u32), u32>,
+    corners: [u32; 3],
+    weights: [u32; 3],
+    segments: u32,
+) -> u32 {
+    let [a, b, c] = corners;
+    let [wa, wb, wc] = weights;
+
+    // Points exactly on a corner of the base icosahedron are the corner itself.
+    if wb == 0 && wc == 0 {
+        return a;
+    }
+    if wa == 0 && wc == 0 {
+        return b;
+    }
+    if wa == 0 && wb == 0 {
+        return c;
+    }
+
+    // Points on an edge are shared by exactly two faces; key them by the sorted
+    // pair of corner indices and the barycentric weight of the lower one so both faces
+    // resolve to the same generated vertex.
+    let edge = if wc == 0 {
+        Some((a, b, wa))
+    } else if wb == 0 {
+        Some((a, c, wa))
+    } else if wa == 0 {
+        Some((b, c, wb))
+    } else {
+        None
+    };
+
+    if let Some((p, q, w_p)) = edge {
+        let key = if p < q {
+            (p, q, w_p)
+        } else {
+            (q, p, segments - w_p)
+        };
+
+        if let Some(&index) = shared_edge_points.get(&key) {
+            return index;
+        }
+
+        let index = directions.len() as u32;
+        directions.push(interpolate_direction(directions, corners, weights, segments));
+        shared_edge_points.insert(key, index);
+        return index;
+    }
+
+    // Interior points are unique to this face and are never shared.
+    let index = directions.len() as u32;
+    directions.push(interpolate_direction(directions, corners, weights, segments));
+    index
+}
+
+/// Interpolates the barycentric point at `weights` (summing to `segments`) on
+/// the face `corners`, then projects it onto the unit sphere.
+fn interpolate_direction(
+    directions: &[Vec3],
+    corners: [u32; 3],
+    weights: [u32; 3],
+    segments: u32,
+) -> Vec3 {
+    let [a, b, c] = corners;
+    let [wa, wb, wc] = weights;
+
+    let point = (directions[a as usize] * wa as f32
+        + directions[b as usize] * wb as f32
+        + directions[c as usize] * wc as f32)
+        / segments as f32;
+
+    point.normalize()
+}
+
+/// Duplicates vertices on the `u = 0` / `u = 1` UV seam so that triangles which
+/// straddle it sample a continuous range of `u` instead of wrapping around.
+fn duplicate_uv_seam(
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    uvs: &mut Vec<[f32; 2]>,
+    indices: &mut [u32],
+) {
+    // Keyed by (original vertex, integer shift) since the same vertex can need
+    // a different shift relative to different triangles' reference corners.
+    let mut duplicates: HashMap<(u32, i32), u32> = HashMap::new();
+
+    for triangle in indices.chunks_mut(3) {
+        // Unwrap the other two corners relative to the triangle's first corner
+        // rather than against a fixed global split: a vertex more than half
+        // the texture away from the reference is the one that wrapped around
+        // the seam, regardless of whether it or the reference sits exactly on
+        // the `u = 0.5` boundary or the ambiguous `atan2` branch (`u ≈ 1.0`).
+        let reference_u = uvs[triangle[0] as usize][0];
+
+        for vertex in &mut triangle[1..] {
+            let u = uvs[*vertex as usize][0];
+            let shift = if u - reference_u > 0.5 {
+                -1
+            } else if u - reference_u < -0.5 {
+                1
+            } else {
+                continue;
+            };
+
+            *vertex = *duplicates.entry((*vertex, shift)).or_insert_with(|| {
+                let position = positions[*vertex as usize];
+                let normal = normals[*vertex as usize];
+                let uv = uvs[*vertex as usize];
+
+                let index = positions.len() as u32;
+                positions.push(position);
+                normals.push(normal);
+                uvs.push([uv[0] + shift as f32, uv[1]]);
+                index
+            });
+        }
+    }
+}
+
+/// Builds the [`EllipsoidMeshlet`] descriptor for the meshlet whose unique
+/// vertices occupy `vertex_ids[vertex_start..]` and whose local triangle
+/// indices occupy `triangles[triangle_start..]`, computing its bounding
+/// sphere from the mesh's `positions`.
+fn finish_meshlet(
+    vertex_start: usize,
+    triangle_start: usize,
+    vertex_ids: &[u32],
+    triangles: &[u8],
+    positions: &[[f32; 3]],
+) -> EllipsoidMeshlet {
+    let meshlet_vertex_ids = &vertex_ids[vertex_start..];
+    let meshlet_position = |&global: &u32| Vec3::from_array(positions[global as usize]);
+
+    let center = meshlet_vertex_ids.iter().map(meshlet_position).sum::<Vec3>()
+        / meshlet_vertex_ids.len() as f32;
+    let radius = meshlet_vertex_ids
+        .iter()
+        .map(|global| meshlet_position(global).distance(center))
+        .fold(0.0f32, f32::max);
+
+    EllipsoidMeshlet {
+        vertex_offset: vertex_start as u32,
+        vertex_count: (vertex_ids.len() - vertex_start) as u32,
+        triangle_offset: triangle_start as u32,
+        triangle_count: ((triangles.len() - triangle_start) / 3) as u32,
+        bounding_sphere_center: center.to_array(),
+        bounding_sphere_radius: radius,
+    }
 }
 
 impl MeshBuilder for EllipsoidMeshBuilder {
     /// Builds a [`Mesh`] according to the configuration in `self`.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the sphere is a [`SphereKind::Ico`] with a subdivision count
-    /// that is greater than or equal to `80` because there will be too many vertices.
     fn build(&self) -> Mesh {
-        self.uv(self.options.sectors, self.options.stacks)
+        match self.options.kind {
+            EllipsoidKind::Uv { sectors, stacks } => self.uv(sectors, stacks),
+            EllipsoidKind::Ico { subdivisions } => self.ico(subdivisions),
+        }
     }
 }
 
@@ -148,3 +759,169 @@ impl From<Ellipsoid> for Mesh {
         ellipsoid.mesh().build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle_count(mesh: &Mesh) -> usize {
+        match mesh.indices() {
+            Some(Indices::U32(indices)) => indices.len() / 3,
+            _ => 0,
+        }
+    }
+
+    fn vertex_count(mesh: &Mesh) -> usize {
+        match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float32x3(positions)) => positions.len(),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn ico_triangle_count_matches_icosphere_formula_and_is_manifold() {
+        for subdivisions in [0, 1, 2, 3] {
+            let segments = subdivisions + 1;
+            let mesh = EllipsoidMeshBuilder::new(1.0, 2.0, 3.0).ico(subdivisions);
+
+            // The triangle count follows the standard icosphere formula exactly.
+            // The vertex count is only a lower bound: the UV seam-duplication
+            // pass legitimately adds a handful of extra vertices on top of the
+            // underlying geodesic grid.
+            assert_eq!(triangle_count(&mesh), 20 * segments * segments);
+            assert!(vertex_count(&mesh) >= 10 * segments * segments + 2);
+
+            let Some(Indices::U32(indices)) = mesh.indices() else {
+                panic!("ico mesh is missing indices");
+            };
+            assert!(indices.iter().all(|&i| (i as usize) < vertex_count(&mesh)));
+        }
+    }
+
+    #[test]
+    fn ico_uv_seam_triangles_never_span_more_than_half_the_texture() {
+        let mesh = EllipsoidMeshBuilder::new(1.0, 1.0, 1.0).ico(3);
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("ico mesh is missing UVs");
+        };
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("ico mesh is missing indices");
+        };
+
+        for triangle in indices.chunks_exact(3) {
+            let us: Vec<f32> = triangle.iter().map(|&i| uvs[i as usize][0]).collect();
+            let max_u = us.iter().copied().fold(f32::MIN, f32::max);
+            let min_u = us.iter().copied().fold(f32::MAX, f32::min);
+            assert!(
+                max_u - min_u <= 0.5,
+                "triangle UVs {us:?} span more than half the texture"
+            );
+        }
+    }
+
+    #[test]
+    fn displaced_with_zero_height_keeps_normals_pointing_outward() {
+        // Built from `ico` rather than `uv` so every vertex is referenced by at
+        // least one triangle (the `uv` pole rings duplicate a vertex that isn't
+        // used by any triangle, which would trivially fail the check below).
+        let builder = EllipsoidMeshBuilder::new(1.0, 1.0, 1.0);
+        let mesh = builder.ico(2);
+        let displaced = builder.displaced(mesh, |_direction| 0.0, DisplacementOptions::default());
+
+        let (
+            Some(VertexAttributeValues::Float32x3(positions)),
+            Some(VertexAttributeValues::Float32x3(normals)),
+        ) = (
+            displaced.attribute(Mesh::ATTRIBUTE_POSITION),
+            displaced.attribute(Mesh::ATTRIBUTE_NORMAL),
+        )
+        else {
+            panic!("displaced mesh is missing attributes");
+        };
+
+        for (position, normal) in positions.iter().zip(normals) {
+            let position = Vec3::from_array(*position);
+            let normal = Vec3::from_array(*normal);
+            assert!((normal.length() - 1.0).abs() < 0.01);
+            assert!(normal.dot(position.normalize()) > 0.9);
+        }
+    }
+
+    #[test]
+    fn displaced_with_recomputed_uvs_reapplies_the_seam_duplication_pass() {
+        let builder = EllipsoidMeshBuilder::new(1.0, 1.0, 1.0);
+        let mesh = builder.ico(3);
+        let displaced = builder.displaced(
+            mesh,
+            |_direction| 0.0,
+            DisplacementOptions {
+                keep_original_uvs: false,
+            },
+        );
+
+        let Some(VertexAttributeValues::Float32x2(uvs)) = displaced.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("displaced mesh is missing UVs");
+        };
+        let Some(Indices::U32(indices)) = displaced.indices() else {
+            panic!("displaced mesh is missing indices");
+        };
+
+        for triangle in indices.chunks_exact(3) {
+            let us: Vec<f32> = triangle.iter().map(|&i| uvs[i as usize][0]).collect();
+            let max_u = us.iter().copied().fold(f32::MIN, f32::max);
+            let min_u = us.iter().copied().fold(f32::MAX, f32::min);
+            assert!(
+                max_u - min_u <= 0.5,
+                "triangle UVs {us:?} span more than half the texture"
+            );
+        }
+    }
+
+    #[test]
+    fn build_lods_stops_once_further_levels_would_be_identical() {
+        let mut builder = EllipsoidMeshBuilder::new(1.0, 1.0, 1.0);
+        builder.options.kind = EllipsoidKind::Uv {
+            sectors: 4,
+            stacks: 3,
+        };
+
+        let lods = builder.build_lods(10);
+
+        assert_eq!(lods.len(), 2);
+        assert!(vertex_count(&lods[0]) > vertex_count(&lods[1]));
+    }
+
+    #[test]
+    fn build_meshlets_respects_caps_and_preserves_triangles() {
+        let builder = EllipsoidMeshBuilder::new(1.0, 1.0, 1.0);
+        let mesh = builder.ico(2);
+        let meshlets = builder.build_meshlets(&mesh);
+
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("mesh is missing indices");
+        };
+
+        let mut total_triangles = 0;
+        let mut reconstructed = Vec::new();
+        for meshlet in &meshlets.meshlets {
+            assert!(meshlet.triangle_count as usize <= MESHLET_MAX_TRIANGLES);
+            assert!(meshlet.vertex_count as usize <= MESHLET_MAX_VERTICES);
+
+            let vertex_ids = &meshlets.vertex_ids[meshlet.vertex_offset as usize
+                ..(meshlet.vertex_offset + meshlet.vertex_count) as usize];
+            let local_triangles = &meshlets.triangles[meshlet.triangle_offset as usize
+                ..(meshlet.triangle_offset + meshlet.triangle_count * 3) as usize];
+
+            for &local in local_triangles {
+                reconstructed.push(vertex_ids[local as usize]);
+            }
+
+            total_triangles += meshlet.triangle_count as usize;
+        }
+
+        assert_eq!(total_triangles, indices.len() / 3);
+        assert_eq!(&reconstructed, indices);
+    }
+}